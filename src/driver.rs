@@ -0,0 +1,344 @@
+//! The `episodic` driver: a small layered CLI front end.
+//!
+//! The layering mirrors clippy's `CompilerCalls`/`RustcDefaultCalls` split:
+//! a [`Driver`] wraps the default behaviour ([`DefaultCalls`]) with a chain
+//! of [`EarlyCallback`]s that each get first look at the parsed [`Options`]
+//! before the default behaviour runs. A callback can short-circuit the run
+//! (e.g. to print help or version text) by returning
+//! [`Compilation::Stop`], or let the chain continue with
+//! [`Compilation::Continue`].
+
+use std::env;
+use std::fmt;
+
+use crate::severity;
+
+/// The environment variable episodic checks to tell that it is analysing
+/// its own source tree (the dogfood test sets it when launching the
+/// binary), so it can skip steps that would
+/// otherwise cause it to re-invoke itself per target (e.g. the cargo
+/// subcommand's workspace re-dispatch).
+pub const DOGFOOD_ENV_VAR: &str = "EPISODIC_DOGFOOD";
+
+/// Returns `true` if episodic is currently analysing its own source tree.
+pub fn is_dogfooding() -> bool {
+    env::var_os(DOGFOOD_ENV_VAR).is_some()
+}
+
+/// Whether the driver should keep going after a callback has run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compilation {
+    /// Keep dispatching to the next callback / the default behaviour.
+    Continue,
+    /// Stop here; the callback has already produced the user-visible
+    /// output (help text, version string, an early error, ...).
+    Stop,
+}
+
+/// Options parsed from the arguments the user passed to `episodic` itself,
+/// as opposed to the arguments destined for the underlying work (everything
+/// after a `--` separator).
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub help: bool,
+    pub version: bool,
+    pub subcommand: Option<String>,
+    /// Analyse every target in the package (tests, examples, benches, ...),
+    /// not just the library/binary. Set by `--all-targets`; the cargo
+    /// subcommand mode sets this on every target it re-dispatches to.
+    pub all_targets: bool,
+    /// Analyse the package as if every feature were enabled. Set by
+    /// `--all-features`; same cargo-subcommand usage as `all_targets`.
+    pub all_features: bool,
+    /// `-D`/`-W`/`-A` selectors, in the order they were given.
+    pub severity_rules: Vec<severity::Rule>,
+    /// Arguments following the `--` separator, forwarded verbatim.
+    pub forwarded: Vec<String>,
+}
+
+impl Options {
+    /// Splits `args` into episodic's own flags and the forwarded tail.
+    ///
+    /// The first `--` encountered marks the boundary; everything after it
+    /// is forwarded untouched, even if it looks like an episodic flag.
+    pub fn parse<I>(args: I) -> Self
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut opts = Options::default();
+        let mut args = args.into_iter().peekable();
+
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                break;
+            }
+            match arg.as_str() {
+                "-h" | "--help" => opts.help = true,
+                "-V" | "--version" => opts.version = true,
+                "--all-targets" => opts.all_targets = true,
+                "--all-features" => opts.all_features = true,
+                "-D" | "--deny" | "-W" | "--warn" | "-A" | "--allow" => {
+                    let level = match arg.as_str() {
+                        "-D" => severity::Level::from_flag('D').expect("'D' is a valid flag"),
+                        "-W" => severity::Level::from_flag('W').expect("'W' is a valid flag"),
+                        "-A" => severity::Level::from_flag('A').expect("'A' is a valid flag"),
+                        "--deny" => severity::Level::Deny,
+                        "--warn" => severity::Level::Warn,
+                        _ => severity::Level::Allow,
+                    };
+                    // Don't let a missing selector swallow the `--`
+                    // forwarding separator or the next episodic flag.
+                    if args
+                        .peek()
+                        .is_some_and(|next| next != "--" && !next.starts_with('-'))
+                    {
+                        let selector = args.next().expect("peek confirmed a value is present");
+                        opts.severity_rules
+                            .push(severity::Rule::new(level, selector));
+                    }
+                }
+                _ if opts.subcommand.is_none() && !arg.starts_with('-') => {
+                    opts.subcommand = Some(arg);
+                }
+                _ => {}
+            }
+        }
+
+        opts.forwarded.extend(args);
+        opts
+    }
+}
+
+/// First look at the parsed [`Options`] before the default behaviour runs.
+///
+/// Implementations decide whether to short-circuit the run (help, version,
+/// an early validation failure) or let it continue.
+pub trait EarlyCallback {
+    fn early(&self, opts: &Options) -> Compilation;
+}
+
+/// Prints `--help`/`--version` and stops the run when either is set.
+pub struct HelpAndVersion;
+
+impl EarlyCallback for HelpAndVersion {
+    fn early(&self, opts: &Options) -> Compilation {
+        if opts.version {
+            println!("episodic {}", env!("CARGO_PKG_VERSION"));
+            return Compilation::Stop;
+        }
+        if opts.help {
+            println!("{}", Driver::usage());
+            return Compilation::Stop;
+        }
+        Compilation::Continue
+    }
+}
+
+/// The default behaviour once no early callback has stopped the run.
+///
+/// This is the `RustcDefaultCalls` analogue. No subcommands are
+/// registered, so any name in [`Options::subcommand`] is reported as
+/// [`DriverError::UnknownSubcommand`]; with no subcommand it prints usage.
+/// Returns findings so [`Driver::run`] can weigh them against the
+/// configured severity rules; the list it returns is always empty.
+pub struct DefaultCalls;
+
+impl DefaultCalls {
+    fn run(&self, opts: &Options) -> Result<Vec<severity::Finding>, DriverError> {
+        match opts.subcommand.as_deref() {
+            Some(name) => Err(DriverError::UnknownSubcommand(name.to_owned())),
+            None => {
+                println!("{}", Driver::usage());
+                Ok(Vec::new())
+            }
+        }
+    }
+}
+
+/// Errors the driver can surface after dispatch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DriverError {
+    UnknownSubcommand(String),
+    /// At least one finding resolved to [`severity::Level::Deny`] under
+    /// the configured `-D`/`-W`/`-A` rules.
+    DeniedFindings(Vec<String>),
+}
+
+impl fmt::Display for DriverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DriverError::UnknownSubcommand(name) => {
+                write!(f, "unknown subcommand `{name}`")
+            }
+            DriverError::DeniedFindings(names) => {
+                write!(f, "denied findings: {}", names.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for DriverError {}
+
+/// Wraps [`DefaultCalls`] with a chain of [`EarlyCallback`]s that run first.
+pub struct Driver {
+    callbacks: Vec<Box<dyn EarlyCallback>>,
+    default: DefaultCalls,
+}
+
+impl Default for Driver {
+    fn default() -> Self {
+        Driver {
+            callbacks: Vec::new(),
+            default: DefaultCalls,
+        }
+    }
+}
+
+impl Driver {
+    pub fn new() -> Self {
+        Driver::default().with_callback(Box::new(HelpAndVersion))
+    }
+
+    /// Registers an additional early callback, run after the built-in ones
+    /// and in registration order.
+    pub fn with_callback(mut self, callback: Box<dyn EarlyCallback>) -> Self {
+        self.callbacks.push(callback);
+        self
+    }
+
+    fn usage() -> &'static str {
+        "Usage: episodic [OPTIONS] [SUBCOMMAND] [-- <args>...]\n\n\
+         Options:\n  \
+         -h, --help               Print help\n  \
+         -V, --version            Print version\n  \
+         --all-targets            Analyse every target (tests, examples, benches, ...)\n  \
+         --all-features           Analyse with every feature enabled\n  \
+         -D, --deny <SELECTOR>    Deny a lint or group (repeatable)\n  \
+         -W, --warn <SELECTOR>    Warn on a lint or group (repeatable)\n  \
+         -A, --allow <SELECTOR>   Allow a lint or group (repeatable)\n"
+    }
+
+    /// Parses `args`, then runs via [`Driver::run_with_options`].
+    pub fn run<I>(&self, args: I) -> Result<(), DriverError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        self.run_with_options(Options::parse(args))
+    }
+
+    /// Runs every early callback in turn against an already-parsed `opts`,
+    /// falling through to the default behaviour unless one of them stops
+    /// the run. Findings the default behaviour produces are then weighed
+    /// against the configured `-D`/`-W`/`-A` rules, failing the run if any
+    /// resolve to deny.
+    ///
+    /// Exposed separately from [`Driver::run`] so callers that build
+    /// `Options` programmatically — the cargo subcommand mode scoping a
+    /// dispatch to one workspace target, for instance — don't have to
+    /// round-trip through a flattened argument vector just to hand off to
+    /// the driver.
+    pub fn run_with_options(&self, opts: Options) -> Result<(), DriverError> {
+        for callback in &self.callbacks {
+            if callback.early(&opts) == Compilation::Stop {
+                return Ok(());
+            }
+        }
+
+        let findings = self.default.run(&opts)?;
+        let severity = severity::SeverityConfig::new(opts.severity_rules.clone());
+        let denied = severity.denied(&findings);
+        if denied.is_empty() {
+            Ok(())
+        } else {
+            Err(DriverError::DeniedFindings(
+                denied.into_iter().map(|f| f.name.clone()).collect(),
+            ))
+        }
+    }
+
+    /// Convenience entry point: runs against the real process arguments,
+    /// skipping argv[0].
+    pub fn run_from_env() -> Result<(), DriverError> {
+        Driver::new().run(env::args().skip(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(raw: &[&str]) -> Vec<String> {
+        raw.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn splits_forwarded_args_on_separator() {
+        let opts = Options::parse(args(&["check", "--", "--edition", "2021"]));
+        assert_eq!(opts.subcommand.as_deref(), Some("check"));
+        assert_eq!(opts.forwarded, args(&["--edition", "2021"]));
+    }
+
+    #[test]
+    fn help_flag_is_recognised() {
+        let opts = Options::parse(args(&["--help"]));
+        assert!(opts.help);
+        assert!(opts.subcommand.is_none());
+    }
+
+    #[test]
+    fn no_subcommand_falls_through_to_default() {
+        let driver = Driver::new();
+        assert!(driver.run(args(&[])).is_ok());
+    }
+
+    #[test]
+    fn unknown_subcommand_is_an_error() {
+        let driver = Driver::new();
+        let err = driver.run(args(&["bogus"])).unwrap_err();
+        assert_eq!(err, DriverError::UnknownSubcommand("bogus".to_owned()));
+    }
+
+    #[test]
+    fn severity_flags_are_collected_in_order() {
+        let opts = Options::parse(args(&[
+            "-D",
+            "all",
+            "-D",
+            "clippy::pedantic",
+            "-A",
+            "some::lint",
+        ]));
+        assert_eq!(
+            opts.severity_rules,
+            vec![
+                severity::Rule::new(severity::Level::Deny, "all"),
+                severity::Rule::new(severity::Level::Deny, "clippy::pedantic"),
+                severity::Rule::new(severity::Level::Allow, "some::lint"),
+            ]
+        );
+    }
+
+    #[test]
+    fn severity_flag_missing_selector_does_not_swallow_separator() {
+        let opts = Options::parse(args(&["-D", "--", "check"]));
+        assert!(opts.severity_rules.is_empty());
+        assert_eq!(opts.forwarded, args(&["check"]));
+    }
+
+    #[test]
+    fn all_targets_and_all_features_flags_are_recognised() {
+        let opts = Options::parse(args(&["--all-targets", "--all-features", "check"]));
+        assert!(opts.all_targets);
+        assert!(opts.all_features);
+        assert_eq!(opts.subcommand.as_deref(), Some("check"));
+    }
+
+    #[test]
+    fn no_findings_means_no_denied_findings_error() {
+        // `DefaultCalls` doesn't emit findings yet, so with no subcommand
+        // the severity rules have nothing to act on and the run succeeds
+        // even with a blanket deny in place.
+        let driver = Driver::new();
+        assert!(driver.run(args(&["-D", "all"])).is_ok());
+    }
+}
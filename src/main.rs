@@ -1,10 +1,51 @@
 //! Entrypoint for the episodic application binary.
 
-/// Welcome to Episodic!
-#[expect(
-    clippy::print_stdout,
-    reason = "Startup placeholder output until the CLI is implemented."
-)]
-fn main() {
-    println!("Hello from Episodic!");
+mod cargo;
+mod driver;
+mod severity;
+
+use std::env;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let cargo_env_present = env::var_os("CARGO").is_some();
+
+    if !driver::is_dogfooding() && cargo::invoked_via_cargo(&args, cargo_env_present) {
+        return run_as_cargo_subcommand(&args);
+    }
+
+    match driver::Driver::run_from_env() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Handles the `cargo episodic` shape: strips the injected subcommand
+/// token, resolves the workspace, and re-dispatches across every target.
+fn run_as_cargo_subcommand(args: &[String]) -> ExitCode {
+    let args = cargo::strip_subcommand_token(args);
+    let opts = driver::Options::parse(args);
+
+    let targets = match cargo::resolve_workspace() {
+        Ok(targets) => targets,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let driver = driver::Driver::new();
+    for target in &targets {
+        let dispatch_opts = cargo::options_for_target(&opts, target);
+        if let Err(err) = driver.run_with_options(dispatch_opts) {
+            eprintln!("error: {} ({}): {err}", target.package, target.name);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,159 @@
+//! Severity levels for findings, configurable via `-D`/`-W`/`-A` flags.
+//!
+//! Mirrors how clippy users pass `-D clippy::all -D clippy::pedantic`: each
+//! flag names a selector (a specific lint/check name, or a group alias such
+//! as `all` or `pedantic`) at a given [`Level`]. Rules are kept in the
+//! order they were given and applied last-wins to each finding, so a later,
+//! more specific `-A some::lint` can override an earlier `-D all`.
+
+use std::fmt;
+
+/// How a finding should be treated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl Level {
+    /// Parses the flag letter (`D`, `W`, `A`) used on the command line.
+    pub fn from_flag(flag: char) -> Option<Self> {
+        match flag {
+            'D' => Some(Level::Deny),
+            'W' => Some(Level::Warn),
+            'A' => Some(Level::Allow),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Level::Allow => "allow",
+            Level::Warn => "warn",
+            Level::Deny => "deny",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// The default level applied to a finding that no rule matches.
+pub const DEFAULT_LEVEL: Level = Level::Warn;
+
+/// A single `-D`/`-W`/`-A <selector>` rule, in the order it was given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub level: Level,
+    pub selector: String,
+}
+
+impl Rule {
+    pub fn new(level: Level, selector: impl Into<String>) -> Self {
+        Rule {
+            level,
+            selector: selector.into(),
+        }
+    }
+
+    /// Whether this rule's selector matches a finding named `name` that
+    /// belongs to the given `groups` (e.g. `["all", "pedantic"]`).
+    fn matches(&self, name: &str, groups: &[&str]) -> bool {
+        self.selector == name || groups.contains(&self.selector.as_str())
+    }
+}
+
+/// A finding emitted by a subcommand, named and grouped the same way a
+/// [`Rule`] selector matches against it. Subcommands don't exist yet that
+/// emit these, but the severity machinery is wired up to consume them as
+/// soon as they do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub name: String,
+    pub groups: Vec<String>,
+}
+
+/// An ordered set of severity rules, applied last-wins.
+#[derive(Debug, Clone, Default)]
+pub struct SeverityConfig {
+    rules: Vec<Rule>,
+}
+
+impl SeverityConfig {
+    pub fn new(rules: Vec<Rule>) -> Self {
+        SeverityConfig { rules }
+    }
+
+    /// Resolves the effective level for a finding named `name` belonging to
+    /// `groups`, applying rules in order so the last match wins. Falls
+    /// back to [`DEFAULT_LEVEL`] when nothing matches.
+    pub fn resolve(&self, name: &str, groups: &[&str]) -> Level {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(name, groups))
+            .map_or(DEFAULT_LEVEL, |rule| rule.level)
+    }
+
+    /// Resolves every finding's level and returns those that resolved to
+    /// [`Level::Deny`], for reporting which findings caused the failure.
+    pub fn denied<'a>(&self, findings: &'a [Finding]) -> Vec<&'a Finding> {
+        findings
+            .iter()
+            .filter(|finding| {
+                let groups: Vec<&str> = finding.groups.iter().map(String::as_str).collect();
+                self.resolve(&finding.name, &groups) == Level::Deny
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unmatched_finding_gets_default_level() {
+        let config = SeverityConfig::default();
+        assert_eq!(config.resolve("some::lint", &["all"]), DEFAULT_LEVEL);
+    }
+
+    #[test]
+    fn later_exact_selector_overrides_earlier_group() {
+        let config = SeverityConfig::new(vec![
+            Rule::new(Level::Deny, "all"),
+            Rule::new(Level::Allow, "some::lint"),
+        ]);
+        assert_eq!(config.resolve("some::lint", &["all"]), Level::Allow);
+        assert_eq!(config.resolve("other::lint", &["all"]), Level::Deny);
+    }
+
+    #[test]
+    fn later_rule_overrides_earlier_one() {
+        let config = SeverityConfig::new(vec![
+            Rule::new(Level::Allow, "pedantic"),
+            Rule::new(Level::Deny, "pedantic"),
+        ]);
+        assert_eq!(config.resolve("some::lint", &["pedantic"]), Level::Deny);
+    }
+
+    #[test]
+    fn denied_reports_only_deny_level_findings() {
+        let config = SeverityConfig::new(vec![
+            Rule::new(Level::Deny, "all"),
+            Rule::new(Level::Allow, "some::allowed"),
+        ]);
+        let findings = vec![
+            Finding {
+                name: "some::allowed".to_owned(),
+                groups: vec!["all".to_owned()],
+            },
+            Finding {
+                name: "some::denied".to_owned(),
+                groups: vec!["all".to_owned()],
+            },
+        ];
+        assert_eq!(config.denied(&findings), vec![&findings[1]]);
+    }
+}
@@ -0,0 +1,49 @@
+//! Runs the freshly built episodic binary over its own source tree.
+//!
+//! Ported from clippy's dogfood test: if episodic can't cleanly analyse
+//! its own sources with the strictest settings, it's not ready to ship.
+//! Sets `EPISODIC_DOGFOOD=1` so the binary knows it is self-analysing and
+//! skips the cargo-subcommand re-dispatch that would otherwise recurse
+//! into itself per target. Skips rather than fails when the built binary
+//! isn't available, since not every CI job builds the binary first.
+//!
+//! There is no `check` subcommand yet, so this can't actually analyse
+//! anything: it runs with no subcommand (the `-D warnings` selector, given
+//! before `--` so it parses as episodic's own flag rather than forwarded
+//! input, is currently a no-op against the empty finding list `DefaultCalls`
+//! always returns). This only proves the driver's own plumbing survives a
+//! self-invocation; it becomes a real regression test once a `check`
+//! subcommand exists.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+#[test]
+fn dogfood() {
+    let Some(bin) = episodic_binary() else {
+        eprintln!("skipping dogfood test: built episodic binary not found");
+        return;
+    };
+
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+
+    let status = Command::new(bin)
+        .args(["-D", "warnings"])
+        .current_dir(manifest_dir)
+        .env("EPISODIC_DOGFOOD", "1")
+        .status()
+        .expect("failed to launch episodic for dogfooding");
+
+    assert!(
+        status.success(),
+        "episodic found issues in its own source tree"
+    );
+}
+
+/// Locates the built episodic binary under `target/$PROFILE/`, returning
+/// `None` if it hasn't been built (e.g. this test ran without `cargo
+/// build` first).
+fn episodic_binary() -> Option<PathBuf> {
+    let path = PathBuf::from(env!("CARGO_BIN_EXE_episodic"));
+    path.is_file().then_some(path)
+}
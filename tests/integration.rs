@@ -0,0 +1,72 @@
+//! Runs episodic against real external repositories.
+//!
+//! Gated behind the `integration` feature so a plain `cargo test` stays
+//! fast and hermetic. Set `INTEGRATION=owner/repo` to shallow-clone that
+//! repository into a temp directory, run the episodic binary over it, and
+//! assert the process neither panics nor ICEs. CI fans this out across a
+//! small matrix of crates to catch regressions that unit fixtures, being
+//! smaller and hand-picked, tend to miss.
+//!
+//! There is no `check` subcommand yet, so every repo currently hits the
+//! same `DriverError::UnknownSubcommand` path regardless of its contents —
+//! this only proves episodic doesn't panic on its own argument handling,
+//! not that it survives real analysis of large external sources. It
+//! becomes genuine input-dependent coverage once a `check` subcommand
+//! exists.
+#![cfg(feature = "integration")]
+
+use std::env;
+use std::process::Command;
+
+/// Crates exercised when no `INTEGRATION` override is given, mirroring
+/// clippy's own integration matrix: a mix of sizes and dependency shapes.
+const DEFAULT_MATRIX: &[&str] = &["rust-lang/log", "serde-rs/serde", "BurntSushi/ripgrep"];
+
+/// Markers rustc/episodic emit on an internal compiler error, distinct
+/// from ordinary diagnostic output.
+const ICE_MARKERS: &[&str] = &[
+    "panicked at",
+    "internal compiler error",
+    "thread 'main' panicked",
+];
+
+#[test]
+fn does_not_panic_on_external_repositories() {
+    let repos: Vec<String> = match env::var("INTEGRATION") {
+        Ok(repo) => vec![repo],
+        Err(_) => DEFAULT_MATRIX.iter().map(|s| s.to_string()).collect(),
+    };
+
+    for repo in repos {
+        run_against_repo(&repo);
+    }
+}
+
+fn run_against_repo(repo: &str) {
+    let dir = clone_shallow(repo);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_episodic"))
+        .arg("check")
+        .current_dir(dir.path())
+        .output()
+        .unwrap_or_else(|err| panic!("failed to launch episodic for {repo}: {err}"));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if let Some(marker) = ICE_MARKERS.iter().find(|m| stderr.contains(**m)) {
+        panic!("episodic crashed on {repo} (matched `{marker}`)\nargs: check\nstderr:\n{stderr}");
+    }
+}
+
+fn clone_shallow(repo: &str) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().expect("failed to create temp dir for clone");
+    let url = format!("https://github.com/{repo}");
+
+    let status = Command::new("git")
+        .args(["clone", "--depth", "1", &url])
+        .arg(dir.path())
+        .status()
+        .unwrap_or_else(|err| panic!("failed to launch git clone for {repo}: {err}"));
+
+    assert!(status.success(), "failed to clone {repo}");
+    dir
+}